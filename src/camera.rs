@@ -0,0 +1,176 @@
+use bevy::input::mouse::MouseMotion;
+use bevy::prelude::*;
+use bevy::window::{CursorGrabMode, PrimaryWindow};
+use bevy_xpbd_3d::prelude::*;
+
+use crate::Player;
+
+/// Tunable speed/sensitivity knobs for the free-flight camera, in the spirit
+/// of `bevy_flycam`'s `MovementSettings`.
+#[derive(Resource)]
+pub struct MovementSettings {
+    pub sensitivity: f32,
+    pub walk_speed: f32,
+    pub run_speed: f32,
+}
+
+impl Default for MovementSettings {
+    fn default() -> Self {
+        Self {
+            sensitivity: 0.1,
+            walk_speed: 5.0,
+            run_speed: 10.0,
+        }
+    }
+}
+
+/// Remappable keybinds and look state for a free-flight camera. `enabled` is
+/// cleared while the cursor is released so the player can't look/move
+/// through menus or a lost-focus window.
+#[derive(Component)]
+pub struct CameraController {
+    pub pitch: f32,
+    pub yaw: f32,
+    pub key_forward: KeyCode,
+    pub key_back: KeyCode,
+    pub key_left: KeyCode,
+    pub key_right: KeyCode,
+    pub key_up: KeyCode,
+    pub key_down: KeyCode,
+    pub key_run: KeyCode,
+    pub enabled: bool,
+}
+
+impl Default for CameraController {
+    fn default() -> Self {
+        Self {
+            pitch: 0.0,
+            yaw: 0.0,
+            key_forward: KeyCode::KeyW,
+            key_back: KeyCode::KeyS,
+            key_left: KeyCode::KeyA,
+            key_right: KeyCode::KeyD,
+            key_up: KeyCode::KeyE,
+            key_down: KeyCode::KeyQ,
+            key_run: KeyCode::ShiftLeft,
+            enabled: true,
+        }
+    }
+}
+
+pub fn player_movement(
+    keys: Res<ButtonInput<KeyCode>>,
+    settings: Res<MovementSettings>,
+    mut query: Query<(&Transform, &CameraController, &mut LinearVelocity), With<Player>>,
+) {
+    let Ok((transform, controller, mut velocity)) = query.get_single_mut() else {
+        return;
+    };
+    if !controller.enabled {
+        velocity.x = 0.0;
+        velocity.z = 0.0;
+        return;
+    }
+
+    let forward = transform.forward();
+    let right = transform.right();
+    let mut direction = Vec3::ZERO;
+
+    if keys.pressed(controller.key_forward) {
+        direction += *forward;
+    }
+    if keys.pressed(controller.key_back) {
+        direction -= *forward;
+    }
+    if keys.pressed(controller.key_right) {
+        direction += *right;
+    }
+    if keys.pressed(controller.key_left) {
+        direction -= *right;
+    }
+
+    direction.y = 0.0;
+    direction = direction.normalize_or_zero();
+
+    let speed = if keys.pressed(controller.key_run) {
+        settings.run_speed
+    } else {
+        settings.walk_speed
+    };
+    let horizontal = direction * speed;
+    velocity.x = horizontal.x;
+    velocity.z = horizontal.z;
+
+    if keys.pressed(controller.key_up) {
+        velocity.y = speed;
+    } else if keys.pressed(controller.key_down) {
+        velocity.y = -speed;
+    }
+}
+
+pub fn mouse_look(
+    mut events: EventReader<MouseMotion>,
+    settings: Res<MovementSettings>,
+    mut query: Query<(&mut Transform, &mut CameraController)>,
+    time: Res<Time>,
+) {
+    let (mut transform, mut controller) = query.single_mut();
+    if !controller.enabled {
+        events.clear();
+        return;
+    }
+
+    for event in events.read() {
+        controller.yaw -= event.delta.x * settings.sensitivity * time.delta_seconds();
+        controller.pitch += event.delta.y * settings.sensitivity * time.delta_seconds();
+
+        // Clamp pitch to avoid flipping
+        controller.pitch = controller.pitch.clamp(-1.54, 1.54); // ~±88°
+
+        transform.rotation =
+            Quat::from_rotation_y(controller.yaw) * Quat::from_rotation_x(-controller.pitch);
+    }
+}
+
+pub fn initial_grab_cursor(mut windows: Query<&mut Window, With<PrimaryWindow>>) {
+    if let Ok(mut window) = windows.get_single_mut() {
+        grab_cursor(&mut window);
+    }
+}
+
+/// Releases the cursor on Escape, and re-grabs it on the next click back into
+/// the window - the standard flycam toggle dance.
+pub fn cursor_grab_toggle(
+    keys: Res<ButtonInput<KeyCode>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    mut windows: Query<&mut Window, With<PrimaryWindow>>,
+    mut controllers: Query<&mut CameraController>,
+) {
+    let Ok(mut window) = windows.get_single_mut() else {
+        return;
+    };
+
+    if keys.just_pressed(KeyCode::Escape) {
+        release_cursor(&mut window);
+        for mut controller in &mut controllers {
+            controller.enabled = false;
+        }
+    } else if window.cursor.grab_mode == CursorGrabMode::None
+        && buttons.just_pressed(MouseButton::Left)
+    {
+        grab_cursor(&mut window);
+        for mut controller in &mut controllers {
+            controller.enabled = true;
+        }
+    }
+}
+
+fn grab_cursor(window: &mut Window) {
+    window.cursor.grab_mode = CursorGrabMode::Locked;
+    window.cursor.visible = false;
+}
+
+fn release_cursor(window: &mut Window) {
+    window.cursor.grab_mode = CursorGrabMode::None;
+    window.cursor.visible = true;
+}
@@ -1,17 +1,35 @@
 use bevy::input::ButtonInput;
-use bevy::input::mouse::MouseMotion;
-use bevy::math::primitives::Cuboid;
 use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::*;
+
+mod camera;
+mod npc;
+mod physics;
+mod scene_import;
+mod skybox;
+mod voxel;
+
+use camera::{
+    cursor_grab_toggle, initial_grab_cursor, mouse_look, player_movement, CameraController,
+    MovementSettings,
+};
+use npc::{move_npcs, pathfind_npcs, repath_on_block_change, spawn_npcs};
+use physics::{player_jump, player_physics_bundle, update_grounded};
+use scene_import::{cycle_active_camera, load_gltf_scene, register_scene_cameras, SceneCameras};
+use skybox::{asset_loaded, cycle_cubemap, setup_skybox};
+use voxel::{
+    mesh_dirty_chunks, ChunkEntities, ChunkMaterial, VoxelWorld, BLOCK_EMPTY, BLOCK_GRASS,
+};
 
 #[derive(Event)]
-struct PlaceBlockEvent(Vec3);
+struct PlaceBlockEvent(IVec3);
 
 #[derive(Event)]
-struct DespawnBlockEvent(Vec3);
+struct DespawnBlockEvent(IVec3);
 
-const PLAYER_SPEED: f32 = 5.0;
 const JUMP_SPEED: f32 = 5.0;
 const BLOCK_SIZE: f32 = 1.0;
+const MAX_BLOCK_REACH: f32 = 8.0;
 
 fn main() {
     App::new()
@@ -23,18 +41,38 @@ fn main() {
             }),
             ..default()
         }))
-        .add_systems(Startup, setup)
-        .add_systems(Update, (player_movement, mouse_look))
+        .add_plugins(PhysicsPlugins::default())
+        .init_resource::<VoxelWorld>()
+        .init_resource::<ChunkEntities>()
+        .init_resource::<MovementSettings>()
+        .init_resource::<SceneCameras>()
+        .add_systems(
+            Startup,
+            (
+                setup,
+                initial_grab_cursor,
+                setup_skybox,
+                spawn_npcs,
+                load_gltf_scene,
+            ),
+        )
+        .add_systems(Update, (cursor_grab_toggle, player_movement, mouse_look))
+        .add_systems(Update, (asset_loaded, cycle_cubemap))
+        .add_systems(
+            Update,
+            (register_scene_cameras, cycle_active_camera).chain(),
+        )
         .add_systems(
             Update,
             (
-                handle_place_block,
-                handle_despawn_block,
                 place_or_destroy_block,
-                apply_gravity,
-                player_jump,
-            ),
+                (handle_place_block, handle_despawn_block),
+                (mesh_dirty_chunks, repath_on_block_change),
+            )
+                .chain(),
         )
+        .add_systems(Update, (pathfind_npcs, move_npcs).chain())
+        .add_systems(Update, (update_grounded, player_jump).chain())
         .add_event::<PlaceBlockEvent>()
         .add_event::<DespawnBlockEvent>()
         .run();
@@ -45,8 +83,8 @@ struct Player;
 
 fn setup(
     mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    mut voxel_world: ResMut<VoxelWorld>,
 ) {
     // Ambient light
     commands.insert_resource(AmbientLight {
@@ -65,22 +103,17 @@ fn setup(
         ..default()
     });
 
-    // Ground (flat 16x1x16 world)
-    let ground_size = 16;
-    let cube_mesh = meshes.add(Mesh::from(Cuboid::new(1.0, 1.0, 1.0)));
-    let cube_material = materials.add(StandardMaterial {
+    commands.insert_resource(ChunkMaterial(materials.add(StandardMaterial {
         base_color: Color::rgb(0.4, 0.8, 0.4),
         ..default()
-    });
+    })));
 
+    // Ground (flat 16x1x16 world), driven through the voxel world so it
+    // renders as greedily-meshed chunks instead of per-block entities.
+    let ground_size = 16;
     for x in 0..ground_size {
         for z in 0..ground_size {
-            commands.spawn(PbrBundle {
-                mesh: cube_mesh.clone(),
-                material: cube_material.clone(),
-                transform: Transform::from_xyz(x as f32, 0.0, z as f32),
-                ..default()
-            });
+            voxel_world.set_block(IVec3::new(x, 0, z), BLOCK_GRASS);
         }
     }
 
@@ -92,169 +125,205 @@ fn setup(
             ..default()
         },
         Player,
-        CameraController {
-            pitch: 0.0,
-            yaw: 0.0,
-        },
-        Velocity {
-            linvel: Vec3::ZERO,
-            on_ground: true,
-        },
+        CameraController::default(),
+        player_physics_bundle(),
     ));
 }
 
-fn player_movement(
-    keys: Res<ButtonInput<KeyCode>>,
-    time: Res<Time>,
-    mut query: Query<&mut Transform, With<Player>>,
+fn place_or_destroy_block(
+    buttons: Res<ButtonInput<MouseButton>>,
+    camera_query: Query<&Transform, With<Player>>,
+    voxel_world: Res<VoxelWorld>,
+    mut place_writer: EventWriter<PlaceBlockEvent>,
+    mut despawn_writer: EventWriter<DespawnBlockEvent>,
 ) {
-    let mut transform = query.single_mut();
-    let forward = transform.forward();
-    let right = transform.right();
-    let mut direction = Vec3::ZERO;
-
-    if keys.pressed(KeyCode::KeyW) {
-        direction += *forward;
-    }
-    if keys.pressed(KeyCode::KeyS) {
-        direction -= *forward;
-    }
-    if keys.pressed(KeyCode::KeyD) {
-        direction += *right;
-    }
-    if keys.pressed(KeyCode::KeyA) {
-        direction -= *right;
+    let want_destroy = buttons.just_pressed(MouseButton::Left);
+    let want_place = buttons.just_pressed(MouseButton::Right);
+    if !want_destroy && !want_place {
+        return;
     }
 
-    direction.y = 0.0;
-    direction = direction.normalize_or_zero();
+    let camera_transform = camera_query.single();
+    let origin = camera_transform.translation;
+    let direction = *camera_transform.forward();
 
-    transform.translation += direction * PLAYER_SPEED * time.delta_seconds();
+    let Some(hit) = cast_voxel_ray(origin, direction, MAX_BLOCK_REACH, |cell| {
+        voxel_world.is_solid(cell)
+    }) else {
+        return;
+    };
 
-    // Basic jump (no gravity)
-    if keys.just_pressed(KeyCode::Space) {
-        transform.translation.y += JUMP_SPEED * time.delta_seconds();
+    if want_destroy {
+        despawn_writer.send(DespawnBlockEvent(hit.cell));
+    } else {
+        place_writer.send(PlaceBlockEvent(hit.cell + hit.normal));
     }
+}
 
-    // Ground clamp
-    if transform.translation.y < 1.0 {
-        transform.translation.y = 1.0;
-    }
+/// The voxel cell a ray first struck, and the face normal it entered through.
+struct VoxelRayHit {
+    cell: IVec3,
+    normal: IVec3,
 }
 
-fn mouse_look(
-    mut events: EventReader<MouseMotion>,
-    mut query: Query<(&mut Transform, &mut CameraController)>,
-    time: Res<Time>,
-) {
-    let sensitivity = 0.1;
-    let (mut transform, mut controller) = query.single_mut();
+/// Amanatides-Woo voxel traversal: walks unit grid cells along `direction` from
+/// `origin`, stopping at the first cell for which `is_occupied` returns true or
+/// once `max_distance` world units have been crossed.
+fn cast_voxel_ray(
+    origin: Vec3,
+    direction: Vec3,
+    max_distance: f32,
+    is_occupied: impl Fn(IVec3) -> bool,
+) -> Option<VoxelRayHit> {
+    let direction = direction.normalize_or_zero();
+    if direction == Vec3::ZERO {
+        return None;
+    }
 
-    for event in events.read() {
-        controller.yaw -= event.delta.x * sensitivity * time.delta_seconds();
-        controller.pitch += event.delta.y * sensitivity * time.delta_seconds();
+    let step = IVec3::new(
+        axis_step(direction.x),
+        axis_step(direction.y),
+        axis_step(direction.z),
+    );
+    let mut cell = origin.floor().as_ivec3();
+    let mut t_max = Vec3::new(
+        axis_t_max(origin.x, direction.x, step.x),
+        axis_t_max(origin.y, direction.y, step.y),
+        axis_t_max(origin.z, direction.z, step.z),
+    );
+    let t_delta = Vec3::new(
+        axis_t_delta(direction.x),
+        axis_t_delta(direction.y),
+        axis_t_delta(direction.z),
+    );
+    let mut normal = IVec3::ZERO;
 
-        // Clamp pitch to avoid flipping
-        controller.pitch = controller.pitch.clamp(-1.54, 1.54); // ~±88°
+    loop {
+        if is_occupied(cell) {
+            return Some(VoxelRayHit { cell, normal });
+        }
 
-        transform.rotation =
-            Quat::from_rotation_y(controller.yaw) * Quat::from_rotation_x(-controller.pitch);
-    }
-}
-#[derive(Component, Default)]
-struct Velocity {
-    linvel: Vec3,
-    on_ground: bool,
-}
+        let (axis, t) = if t_max.x <= t_max.y && t_max.x <= t_max.z {
+            (0, t_max.x)
+        } else if t_max.y <= t_max.z {
+            (1, t_max.y)
+        } else {
+            (2, t_max.z)
+        };
 
-fn apply_gravity(time: Res<Time>, mut query: Query<(&mut Transform, &mut Velocity)>) {
-    let gravity = -9.81;
-    for (mut transform, mut velocity) in &mut query {
-        if !velocity.on_ground {
-            velocity.linvel.y += gravity * time.delta_seconds();
+        if t > max_distance {
+            return None;
         }
 
-        transform.translation += velocity.linvel * time.delta_seconds();
-
-        // ground collision at y = 1.0 (top of ground cube)
-        if transform.translation.y <= 1.0 {
-            transform.translation.y = 1.0;
-            velocity.linvel.y = 0.0;
-            velocity.on_ground = true;
+        match axis {
+            0 => {
+                cell.x += step.x;
+                t_max.x += t_delta.x;
+                normal = IVec3::new(-step.x, 0, 0);
+            }
+            1 => {
+                cell.y += step.y;
+                t_max.y += t_delta.y;
+                normal = IVec3::new(0, -step.y, 0);
+            }
+            _ => {
+                cell.z += step.z;
+                t_max.z += t_delta.z;
+                normal = IVec3::new(0, 0, -step.z);
+            }
         }
     }
 }
 
-fn player_jump(keys: Res<ButtonInput<KeyCode>>, mut query: Query<&mut Velocity, With<Player>>) {
-    let mut velocity = query.single_mut();
-    if velocity.on_ground && keys.just_pressed(KeyCode::Space) {
-        velocity.linvel.y = 5.0;
-        velocity.on_ground = false;
+/// +1/-1 step direction for a ray axis, or 0 if the ray is parallel to the
+/// other two axes' planes along this one.
+fn axis_step(dir: f32) -> i32 {
+    if dir > 0.0 {
+        1
+    } else if dir < 0.0 {
+        -1
+    } else {
+        0
     }
 }
 
-#[derive(Component)]
-struct CameraController {
-    pitch: f32,
-    yaw: f32,
+/// Ray parameter `t` at which `origin` first crosses a voxel boundary along one axis.
+fn axis_t_max(origin: f32, dir: f32, step: i32) -> f32 {
+    if step == 0 {
+        return f32::INFINITY;
+    }
+    let boundary = if step > 0 {
+        origin.floor() + 1.0
+    } else {
+        origin.floor()
+    };
+    (boundary - origin) / dir
 }
 
-fn place_or_destroy_block(
-    buttons: Res<ButtonInput<MouseButton>>,
-    camera_query: Query<&Transform, With<Player>>,
-    mut place_writer: EventWriter<PlaceBlockEvent>,
-    mut despawn_writer: EventWriter<DespawnBlockEvent>,
-) {
-    let camera_transform = camera_query.single();
-    let origin = camera_transform.translation;
-    let direction = camera_transform.forward();
-
-    for i in 1..10 {
-        let check_pos = (origin + direction * i as f32).floor();
-        let place_pos = (origin + direction * (i as f32 - 1.0)).floor();
-
-        if buttons.just_pressed(MouseButton::Left) {
-            despawn_writer.send(DespawnBlockEvent(check_pos));
-            break;
-        } else if buttons.just_pressed(MouseButton::Right) {
-            place_writer.send(PlaceBlockEvent(place_pos));
-            break;
-        }
+/// `t` distance covered by crossing one full voxel along an axis.
+fn axis_t_delta(dir: f32) -> f32 {
+    if dir == 0.0 {
+        f32::INFINITY
+    } else {
+        (1.0 / dir).abs()
     }
 }
 
 fn handle_place_block(
     mut events: EventReader<PlaceBlockEvent>,
-    mut commands: Commands,
-    mut meshes: ResMut<Assets<Mesh>>,
-    mut materials: ResMut<Assets<StandardMaterial>>,
+    mut voxel_world: ResMut<VoxelWorld>,
 ) {
-    for PlaceBlockEvent(pos) in events.read() {
-        commands.spawn(PbrBundle {
-            mesh: meshes.add(Mesh::from(bevy::math::primitives::Cuboid::new(
-                1.0, 1.0, 1.0,
-            ))),
-            material: materials.add(StandardMaterial {
-                base_color: Color::rgb(0.4, 0.8, 0.4),
-                ..default()
-            }),
-            transform: Transform::from_translation(*pos + Vec3::Y * 0.5),
-            ..default()
-        });
+    for PlaceBlockEvent(cell) in events.read() {
+        voxel_world.set_block(*cell, BLOCK_GRASS);
     }
 }
 
 fn handle_despawn_block(
     mut events: EventReader<DespawnBlockEvent>,
-    mut commands: Commands,
-    blocks: Query<(Entity, &Transform), Without<Player>>,
+    mut voxel_world: ResMut<VoxelWorld>,
 ) {
-    for DespawnBlockEvent(pos) in events.read() {
-        for (entity, transform) in blocks.iter() {
-            if transform.translation.floor() == *pos {
-                commands.entity(entity).despawn();
-                break;
-            }
-        }
+    for DespawnBlockEvent(cell) in events.read() {
+        voxel_world.set_block(*cell, BLOCK_EMPTY);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ray_hits_the_first_occupied_cell() {
+        let hit = cast_voxel_ray(Vec3::new(0.5, 0.5, -5.0), Vec3::Z, 10.0, |cell| {
+            cell == IVec3::new(0, 0, 3)
+        })
+        .expect("ray should hit the occupied cell");
+
+        assert_eq!(hit.cell, IVec3::new(0, 0, 3));
+        assert_eq!(hit.normal, IVec3::new(0, 0, -1));
+    }
+
+    #[test]
+    fn ray_misses_when_nothing_is_occupied_within_range() {
+        let hit = cast_voxel_ray(Vec3::new(0.5, 0.5, 0.5), Vec3::Z, 5.0, |_| false);
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn ray_stops_before_max_distance() {
+        let hit = cast_voxel_ray(Vec3::new(0.5, 0.5, 0.5), Vec3::Z, 2.0, |cell| {
+            cell == IVec3::new(0, 0, 5)
+        });
+        assert!(hit.is_none());
+    }
+
+    #[test]
+    fn axis_t_max_is_infinite_when_the_ray_does_not_move_along_that_axis() {
+        assert_eq!(axis_t_max(3.2, 0.0, 0), f32::INFINITY);
+    }
+
+    #[test]
+    fn axis_t_delta_is_the_time_to_cross_one_full_voxel() {
+        assert_eq!(axis_t_delta(0.5), 2.0);
+        assert_eq!(axis_t_delta(0.0), f32::INFINITY);
     }
 }
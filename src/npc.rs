@@ -0,0 +1,314 @@
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+
+use bevy::prelude::*;
+
+use crate::voxel::VoxelWorld;
+use crate::{DespawnBlockEvent, Player, PlaceBlockEvent};
+
+const NPC_SPEED: f32 = 2.0;
+const WAYPOINT_ARRIVAL_DISTANCE: f32 = 0.1;
+const MAX_PATHFINDING_NODES: usize = 4096;
+
+const HORIZONTAL_DIRECTIONS: [IVec3; 4] = [
+    IVec3::new(1, 0, 0),
+    IVec3::new(-1, 0, 0),
+    IVec3::new(0, 0, 1),
+    IVec3::new(0, 0, -1),
+];
+
+/// A pathfinding agent that chases the player across the voxel terrain.
+#[derive(Component, Default)]
+pub struct Npc {
+    path: Vec<IVec3>,
+    path_index: usize,
+    last_player_cell: IVec3,
+}
+
+pub fn spawn_npcs(
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut materials: ResMut<Assets<StandardMaterial>>,
+) {
+    let mesh = meshes.add(Mesh::from(Capsule3d::new(0.3, 1.0)));
+    let material = materials.add(StandardMaterial {
+        base_color: Color::rgb(0.8, 0.2, 0.2),
+        ..default()
+    });
+
+    for spawn_cell in [IVec3::new(4, 1, 4), IVec3::new(12, 1, 12)] {
+        commands.spawn((
+            PbrBundle {
+                mesh: mesh.clone(),
+                material: material.clone(),
+                transform: Transform::from_translation(spawn_cell.as_vec3() + Vec3::Y * 0.5),
+                ..default()
+            },
+            Npc {
+                // Forces a pathfind on the first frame.
+                last_player_cell: IVec3::MAX,
+                ..default()
+            },
+        ));
+    }
+}
+
+/// Recomputes each NPC's path to the player whenever the player enters a new
+/// standable cell, or whenever its current path has run out.
+pub fn pathfind_npcs(
+    voxel_world: Res<VoxelWorld>,
+    player_query: Query<&Transform, With<Player>>,
+    mut npc_query: Query<(&Transform, &mut Npc)>,
+) {
+    let Ok(player_transform) = player_query.get_single() else {
+        return;
+    };
+    let player_cell = ground_cell(&voxel_world, player_transform.translation);
+
+    for (transform, mut npc) in &mut npc_query {
+        let needs_path = npc.last_player_cell != player_cell
+            || npc.path.is_empty()
+            || npc.path_index >= npc.path.len();
+        if !needs_path {
+            continue;
+        }
+
+        npc.last_player_cell = player_cell;
+        let npc_cell = ground_cell(&voxel_world, transform.translation);
+        npc.path = find_path(&voxel_world, npc_cell, player_cell).unwrap_or_default();
+        npc.path_index = 0;
+    }
+}
+
+/// Steers each NPC toward the next waypoint on its stored path.
+pub fn move_npcs(time: Res<Time>, mut query: Query<(&mut Transform, &mut Npc)>) {
+    for (mut transform, mut npc) in &mut query {
+        let Some(&waypoint) = npc.path.get(npc.path_index) else {
+            continue;
+        };
+
+        let target = waypoint.as_vec3() + Vec3::new(0.5, 0.5, 0.5);
+        let to_target = target - transform.translation;
+        let distance = to_target.length();
+
+        if distance < WAYPOINT_ARRIVAL_DISTANCE {
+            npc.path_index += 1;
+            continue;
+        }
+
+        let step = to_target.normalize() * NPC_SPEED * time.delta_seconds();
+        transform.translation += if step.length() > distance {
+            to_target
+        } else {
+            step
+        };
+    }
+}
+
+/// Invalidates every NPC's path when a block is placed or destroyed, since
+/// the terrain it was computed over may no longer match the world.
+pub fn repath_on_block_change(
+    mut place_events: EventReader<PlaceBlockEvent>,
+    mut despawn_events: EventReader<DespawnBlockEvent>,
+    mut npc_query: Query<&mut Npc>,
+) {
+    if place_events.read().next().is_none() && despawn_events.read().next().is_none() {
+        return;
+    }
+
+    for mut npc in &mut npc_query {
+        npc.path.clear();
+        npc.path_index = 0;
+    }
+}
+
+/// The nearest standable cell at or below a world position, searching at most
+/// 4 cells down; if none of those is standable, returns the cell just below
+/// the searched range.
+fn ground_cell(world: &VoxelWorld, position: Vec3) -> IVec3 {
+    let mut cell = position.floor().as_ivec3();
+    for _ in 0..4 {
+        if is_standable(world, cell) {
+            return cell;
+        }
+        cell.y -= 1;
+    }
+    cell
+}
+
+/// A cell is standable if it's empty and has solid ground directly beneath it.
+fn is_standable(world: &VoxelWorld, cell: IVec3) -> bool {
+    !world.is_solid(cell) && world.is_solid(cell + IVec3::NEG_Y)
+}
+
+fn walkable_neighbors(world: &VoxelWorld, from: IVec3) -> Vec<IVec3> {
+    let mut neighbors = Vec::new();
+    let headroom_clear = !world.is_solid(from + IVec3::Y);
+
+    for dir in HORIZONTAL_DIRECTIONS {
+        let flat = from + dir;
+        if is_standable(world, flat) {
+            neighbors.push(flat);
+        } else if headroom_clear && is_standable(world, flat + IVec3::Y) {
+            neighbors.push(flat + IVec3::Y);
+        } else if is_standable(world, flat + IVec3::NEG_Y) {
+            neighbors.push(flat + IVec3::NEG_Y);
+        }
+    }
+
+    neighbors
+}
+
+/// `f32` cost wrapper ordered so a `BinaryHeap` of it pops the *smallest*
+/// value first, matching A*'s "expand lowest f-score" rule.
+#[derive(PartialEq)]
+struct Cost(f32);
+
+impl Eq for Cost {}
+
+impl PartialOrd for Cost {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Cost {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.0.partial_cmp(&self.0).unwrap_or(Ordering::Equal)
+    }
+}
+
+struct OpenEntry {
+    cost: Cost,
+    node: IVec3,
+}
+
+impl PartialEq for OpenEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for OpenEntry {}
+
+impl PartialOrd for OpenEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for OpenEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.cost.cmp(&other.cost)
+    }
+}
+
+/// A* over walkable voxel columns: cost is Euclidean step distance, the
+/// heuristic is straight-line distance to `goal`.
+fn find_path(world: &VoxelWorld, start: IVec3, goal: IVec3) -> Option<Vec<IVec3>> {
+    if start == goal {
+        return Some(Vec::new());
+    }
+
+    let mut open = BinaryHeap::new();
+    let mut came_from: HashMap<IVec3, IVec3> = HashMap::new();
+    let mut g_score: HashMap<IVec3, f32> = HashMap::new();
+
+    g_score.insert(start, 0.0);
+    open.push(OpenEntry {
+        cost: Cost(heuristic(start, goal)),
+        node: start,
+    });
+
+    let mut expanded = 0;
+    while let Some(OpenEntry { node: current, .. }) = open.pop() {
+        if current == goal {
+            return Some(reconstruct_path(&came_from, current));
+        }
+
+        expanded += 1;
+        if expanded > MAX_PATHFINDING_NODES {
+            return None;
+        }
+
+        for neighbor in walkable_neighbors(world, current) {
+            let tentative_g = g_score[&current] + current.as_vec3().distance(neighbor.as_vec3());
+            if tentative_g < *g_score.get(&neighbor).unwrap_or(&f32::INFINITY) {
+                came_from.insert(neighbor, current);
+                g_score.insert(neighbor, tentative_g);
+                open.push(OpenEntry {
+                    cost: Cost(tentative_g + heuristic(neighbor, goal)),
+                    node: neighbor,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn heuristic(a: IVec3, b: IVec3) -> f32 {
+    a.as_vec3().distance(b.as_vec3())
+}
+
+fn reconstruct_path(came_from: &HashMap<IVec3, IVec3>, mut current: IVec3) -> Vec<IVec3> {
+    let mut path = vec![current];
+    while let Some(&prev) = came_from.get(&current) {
+        current = prev;
+        path.push(current);
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::voxel::BLOCK_GRASS;
+
+    /// A flat floor at `y = -1` spanning `0..width` x `0..depth`, leaving
+    /// `y = 0` empty and standable everywhere.
+    fn flat_floor(width: i32, depth: i32) -> VoxelWorld {
+        let mut world = VoxelWorld::default();
+        for x in 0..width {
+            for z in 0..depth {
+                world.set_block(IVec3::new(x, -1, z), BLOCK_GRASS);
+            }
+        }
+        world
+    }
+
+    #[test]
+    fn flat_ground_has_four_walkable_neighbors() {
+        let world = flat_floor(3, 3);
+        let neighbors = walkable_neighbors(&world, IVec3::new(1, 0, 1));
+        assert_eq!(neighbors.len(), 4);
+    }
+
+    #[test]
+    fn path_detours_around_a_two_high_wall() {
+        let mut world = flat_floor(5, 3);
+        // A wall two blocks tall across z = 0..2 at x = 2 blocks the direct
+        // route, leaving the z = 2 row as the only way through.
+        for z in 0..2 {
+            world.set_block(IVec3::new(2, 0, z), BLOCK_GRASS);
+            world.set_block(IVec3::new(2, 1, z), BLOCK_GRASS);
+        }
+
+        let path = find_path(&world, IVec3::new(0, 0, 0), IVec3::new(4, 0, 0))
+            .expect("a path around the wall should exist");
+
+        assert!(path.iter().any(|cell| cell.x == 2 && cell.z == 2));
+    }
+
+    #[test]
+    fn unreachable_goal_returns_none() {
+        // Two floor islands with a gap between them - no walkable neighbor
+        // can bridge x = 1 to x = 3.
+        let mut world = VoxelWorld::default();
+        world.set_block(IVec3::new(0, -1, 0), BLOCK_GRASS);
+        world.set_block(IVec3::new(3, -1, 0), BLOCK_GRASS);
+
+        assert!(find_path(&world, IVec3::new(0, 0, 0), IVec3::new(3, 0, 0)).is_none());
+    }
+}
@@ -0,0 +1,69 @@
+use bevy::prelude::*;
+use bevy_xpbd_3d::prelude::*;
+
+use crate::{Player, JUMP_SPEED};
+
+const PLAYER_RADIUS: f32 = 0.4;
+const PLAYER_HEIGHT: f32 = 1.0;
+const GROUND_CAST_DISTANCE: f32 = 0.1;
+
+/// Whether the player's ground cast is currently reporting contact.
+#[derive(Component, Default)]
+pub struct Grounded(pub bool);
+
+/// Physics components for the player: a capsule-collider `RigidBody::Dynamic`
+/// with rotation locked (so collisions can't tip it over) and a downward
+/// shape cast used to detect ground contact for jumping.
+pub fn player_physics_bundle() -> impl Bundle {
+    (
+        RigidBody::Dynamic,
+        Collider::capsule(PLAYER_HEIGHT, PLAYER_RADIUS),
+        LockedAxes::ROTATION_LOCKED,
+        ShapeCaster::new(
+            Collider::capsule(PLAYER_HEIGHT * 0.9, PLAYER_RADIUS * 0.9),
+            Vec3::ZERO,
+            Quat::IDENTITY,
+            Direction3d::NEG_Y,
+        )
+        .with_max_time_of_impact(GROUND_CAST_DISTANCE),
+        Grounded::default(),
+    )
+}
+
+/// Builds a single compound collider for a chunk out of one cuboid per solid
+/// block, so one `RigidBody::Static` per chunk collides like a full voxel grid.
+pub fn chunk_collider(solid_cells: impl Iterator<Item = IVec3>) -> Option<Collider> {
+    let shapes: Vec<_> = solid_cells
+        .map(|local| {
+            (
+                local.as_vec3() + Vec3::splat(0.5),
+                Quat::IDENTITY,
+                Collider::cuboid(1.0, 1.0, 1.0),
+            )
+        })
+        .collect();
+
+    if shapes.is_empty() {
+        None
+    } else {
+        Some(Collider::compound(shapes))
+    }
+}
+
+pub fn update_grounded(mut query: Query<(&ShapeHits, &mut Grounded)>) {
+    for (hits, mut grounded) in &mut query {
+        grounded.0 = !hits.is_empty();
+    }
+}
+
+pub fn player_jump(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut query: Query<(&Grounded, &mut LinearVelocity), With<Player>>,
+) {
+    let Ok((grounded, mut velocity)) = query.get_single_mut() else {
+        return;
+    };
+    if grounded.0 && keys.just_pressed(KeyCode::Space) {
+        velocity.y = JUMP_SPEED;
+    }
+}
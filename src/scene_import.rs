@@ -0,0 +1,68 @@
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+
+use crate::camera::CameraController;
+use crate::Player;
+
+/// Drop authored props/characters here and point this at their scene, the
+/// way `scene_viewer` takes a glTF path on the command line.
+const GLTF_SCENE_PATH: &str = "models/scene.glb#Scene0";
+
+/// Every camera found inside the loaded glTF scene, and which camera is
+/// currently active: `0` is always the free-cam, `n` is `cameras[n - 1]`.
+#[derive(Resource, Default)]
+pub struct SceneCameras {
+    cameras: Vec<Entity>,
+    active: usize,
+}
+
+pub fn load_gltf_scene(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.spawn(SceneBundle {
+        scene: asset_server.load(GLTF_SCENE_PATH),
+        ..default()
+    });
+}
+
+/// Picks up any camera newly spawned by the loaded glTF scene and starts it
+/// deactivated; the player's own free-cam is excluded via `Without<Player>`.
+pub fn register_scene_cameras(
+    mut scene_cameras: ResMut<SceneCameras>,
+    new_cameras: Query<Entity, (Added<Camera3d>, Without<Player>)>,
+    mut cameras: Query<&mut Camera>,
+) {
+    for entity in &new_cameras {
+        if let Ok(mut camera) = cameras.get_mut(entity) {
+            camera.is_active = false;
+        }
+        scene_cameras.cameras.push(entity);
+    }
+}
+
+/// `C` cycles the active camera through the free-cam and every camera
+/// loaded from the glTF scene, toggling `Camera::is_active` and the free-cam
+/// controller's `enabled` flag to match.
+pub fn cycle_active_camera(
+    keys: Res<ButtonInput<KeyCode>>,
+    mut scene_cameras: ResMut<SceneCameras>,
+    mut player_query: Query<(&mut Camera, &mut CameraController), With<Player>>,
+    mut scene_camera_query: Query<&mut Camera, Without<Player>>,
+) {
+    if !keys.just_pressed(KeyCode::KeyC) || scene_cameras.cameras.is_empty() {
+        return;
+    }
+
+    scene_cameras.active = (scene_cameras.active + 1) % (scene_cameras.cameras.len() + 1);
+
+    let Ok((mut player_camera, mut controller)) = player_query.get_single_mut() else {
+        return;
+    };
+    let free_cam_active = scene_cameras.active == 0;
+    player_camera.is_active = free_cam_active;
+    controller.enabled = free_cam_active;
+
+    for (index, &entity) in scene_cameras.cameras.iter().enumerate() {
+        if let Ok(mut camera) = scene_camera_query.get_mut(entity) {
+            camera.is_active = scene_cameras.active == index + 1;
+        }
+    }
+}
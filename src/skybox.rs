@@ -0,0 +1,85 @@
+use bevy::core_pipeline::Skybox;
+use bevy::input::ButtonInput;
+use bevy::prelude::*;
+use bevy::render::render_resource::{TextureViewDescriptor, TextureViewDimension};
+
+use crate::Player;
+
+/// Cubemap assets to cycle through with the skybox keybind. Drop matching
+/// files under `assets/skyboxes/` (same layout bevy's own skybox example
+/// expects: a vertical strip of 6 square faces).
+const CUBEMAPS: &[&str] = &[
+    "skyboxes/sky_day.ktx2",
+    "skyboxes/sky_sunset.ktx2",
+    "skyboxes/sky_night.ktx2",
+];
+
+/// Tracks the currently-loading/loaded skybox cubemap.
+#[derive(Resource)]
+pub struct Cubemap {
+    pub is_loaded: bool,
+    index: usize,
+    image_handle: Handle<Image>,
+}
+
+pub fn setup_skybox(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Cubemap {
+        is_loaded: false,
+        index: 0,
+        image_handle: asset_server.load(CUBEMAPS[0]),
+    });
+}
+
+/// Once the current cubemap image has finished loading, reinterprets it as a
+/// `TextureViewDimension::Cube` and attaches/updates the `Skybox` on the
+/// player camera.
+pub fn asset_loaded(
+    asset_server: Res<AssetServer>,
+    mut images: ResMut<Assets<Image>>,
+    mut cubemap: ResMut<Cubemap>,
+    mut commands: Commands,
+    mut player_query: Query<(Entity, Option<&mut Skybox>), With<Player>>,
+) {
+    if cubemap.is_loaded || !asset_server.is_loaded_with_dependencies(&cubemap.image_handle) {
+        return;
+    }
+
+    let image = images.get_mut(&cubemap.image_handle).unwrap();
+    if image.texture_descriptor.array_layer_count() == 1 {
+        image.reinterpret_stacked_2d_as_array(image.height() / image.width());
+        image.texture_view_descriptor = Some(TextureViewDescriptor {
+            dimension: Some(TextureViewDimension::Cube),
+            ..default()
+        });
+    }
+
+    let Ok((player, skybox)) = player_query.get_single_mut() else {
+        return;
+    };
+    match skybox {
+        Some(mut skybox) => skybox.image = cubemap.image_handle.clone(),
+        None => {
+            commands.entity(player).insert(Skybox {
+                image: cubemap.image_handle.clone(),
+                brightness: 1000.0,
+            });
+        }
+    }
+    cubemap.is_loaded = true;
+}
+
+/// Cycles to the next cubemap asset; `asset_loaded` picks up the new handle
+/// once it finishes loading.
+pub fn cycle_cubemap(
+    keys: Res<ButtonInput<KeyCode>>,
+    asset_server: Res<AssetServer>,
+    mut cubemap: ResMut<Cubemap>,
+) {
+    if !keys.just_pressed(KeyCode::KeyK) {
+        return;
+    }
+
+    cubemap.index = (cubemap.index + 1) % CUBEMAPS.len();
+    cubemap.image_handle = asset_server.load(CUBEMAPS[cubemap.index]);
+    cubemap.is_loaded = false;
+}
@@ -0,0 +1,350 @@
+use std::collections::{HashMap, HashSet};
+
+use bevy::prelude::*;
+use bevy::render::mesh::{Indices, PrimitiveTopology};
+use bevy::render::render_asset::RenderAssetUsages;
+use bevy_xpbd_3d::prelude::*;
+
+use crate::physics::chunk_collider;
+
+/// Number of blocks along each edge of a chunk.
+pub const CHUNK_SIZE: i32 = 16;
+
+pub type BlockId = u8;
+
+pub const BLOCK_EMPTY: BlockId = 0;
+pub const BLOCK_GRASS: BlockId = 1;
+
+/// Dense block storage for one 16x16x16 chunk, indexed `[x][y][z]` in local
+/// (chunk-relative) coordinates.
+struct ChunkData {
+    blocks: Box<[[[BlockId; CHUNK_SIZE as usize]; CHUNK_SIZE as usize]; CHUNK_SIZE as usize]>,
+}
+
+impl Default for ChunkData {
+    fn default() -> Self {
+        Self {
+            blocks: Box::new(
+                [[[BLOCK_EMPTY; CHUNK_SIZE as usize]; CHUNK_SIZE as usize]; CHUNK_SIZE as usize],
+            ),
+        }
+    }
+}
+
+impl ChunkData {
+    fn get(&self, local: IVec3) -> BlockId {
+        self.blocks[local.x as usize][local.y as usize][local.z as usize]
+    }
+
+    fn set(&mut self, local: IVec3, id: BlockId) {
+        self.blocks[local.x as usize][local.y as usize][local.z as usize] = id;
+    }
+}
+
+/// Sparse chunk storage for the whole voxel world. Chunks are only allocated
+/// when first written to, so empty regions cost nothing.
+#[derive(Resource, Default)]
+pub struct VoxelWorld {
+    chunks: HashMap<IVec3, ChunkData>,
+    dirty: HashSet<IVec3>,
+}
+
+impl VoxelWorld {
+    fn chunk_and_local(world_pos: IVec3) -> (IVec3, IVec3) {
+        let size = IVec3::splat(CHUNK_SIZE);
+        (world_pos.div_euclid(size), world_pos.rem_euclid(size))
+    }
+
+    pub fn get_block(&self, world_pos: IVec3) -> BlockId {
+        let (chunk, local) = Self::chunk_and_local(world_pos);
+        self.chunks
+            .get(&chunk)
+            .map(|data| data.get(local))
+            .unwrap_or(BLOCK_EMPTY)
+    }
+
+    pub fn is_solid(&self, world_pos: IVec3) -> bool {
+        self.get_block(world_pos) != BLOCK_EMPTY
+    }
+
+    /// Sets a block and marks its chunk (and any neighbor whose shared face
+    /// may have changed visibility) dirty for remeshing.
+    pub fn set_block(&mut self, world_pos: IVec3, id: BlockId) {
+        let (chunk, local) = Self::chunk_and_local(world_pos);
+        self.chunks.entry(chunk).or_default().set(local, id);
+        self.dirty.insert(chunk);
+
+        for axis in 0..3 {
+            if local[axis] == 0 {
+                let mut neighbor = chunk;
+                neighbor[axis] -= 1;
+                self.dirty.insert(neighbor);
+            } else if local[axis] == CHUNK_SIZE - 1 {
+                let mut neighbor = chunk;
+                neighbor[axis] += 1;
+                self.dirty.insert(neighbor);
+            }
+        }
+    }
+
+    pub fn take_dirty_chunks(&mut self) -> Vec<IVec3> {
+        self.dirty.drain().collect()
+    }
+
+    /// Local (chunk-relative) coordinates of every solid block in a chunk.
+    fn solid_local_cells(&self, chunk_coord: IVec3) -> impl Iterator<Item = IVec3> + '_ {
+        let size = CHUNK_SIZE;
+        (0..size).flat_map(move |x| {
+            (0..size).flat_map(move |y| {
+                (0..size).filter_map(move |z| {
+                    let local = IVec3::new(x, y, z);
+                    self.chunks
+                        .get(&chunk_coord)
+                        .filter(|data| data.get(local) != BLOCK_EMPTY)
+                        .map(|_| local)
+                })
+            })
+        })
+    }
+}
+
+/// Entities currently rendering each chunk's mesh, so dirty chunks update
+/// their existing entity instead of spawning a duplicate.
+#[derive(Resource, Default)]
+pub struct ChunkEntities(HashMap<IVec3, Entity>);
+
+/// Shared material every chunk mesh is rendered with.
+#[derive(Resource)]
+pub struct ChunkMaterial(pub Handle<StandardMaterial>);
+
+/// Marker component identifying an entity as a chunk's rendered mesh.
+#[derive(Component)]
+pub struct ChunkMesh;
+
+/// Rebuilds the mesh of every chunk marked dirty this frame via greedy meshing.
+pub fn mesh_dirty_chunks(
+    mut world: ResMut<VoxelWorld>,
+    mut chunk_entities: ResMut<ChunkEntities>,
+    mut commands: Commands,
+    mut meshes: ResMut<Assets<Mesh>>,
+    mut mesh_handles: Query<&mut Handle<Mesh>>,
+    material: Res<ChunkMaterial>,
+) {
+    for chunk_coord in world.take_dirty_chunks() {
+        let mesh = build_chunk_mesh(&world, chunk_coord);
+        let collider = chunk_collider(world.solid_local_cells(chunk_coord));
+        let existing = chunk_entities.0.get(&chunk_coord).copied();
+
+        match (mesh, existing) {
+            (Some(mesh), Some(entity)) => {
+                if let Ok(mut handle) = mesh_handles.get_mut(entity) {
+                    *handle = meshes.add(mesh);
+                }
+                update_chunk_collider(&mut commands, entity, collider);
+            }
+            (Some(mesh), None) => {
+                let mut entity_commands = commands.spawn(PbrBundle {
+                    mesh: meshes.add(mesh),
+                    material: material.0.clone(),
+                    transform: Transform::from_translation((chunk_coord * CHUNK_SIZE).as_vec3()),
+                    ..default()
+                });
+                entity_commands.insert((ChunkMesh, RigidBody::Static));
+                if let Some(collider) = collider {
+                    entity_commands.insert(collider);
+                }
+                chunk_entities.0.insert(chunk_coord, entity_commands.id());
+            }
+            (None, Some(entity)) => {
+                commands.entity(entity).despawn();
+                chunk_entities.0.remove(&chunk_coord);
+            }
+            (None, None) => {}
+        }
+    }
+}
+
+fn update_chunk_collider(commands: &mut Commands, entity: Entity, collider: Option<Collider>) {
+    let mut entity_commands = commands.entity(entity);
+    match collider {
+        Some(collider) => {
+            entity_commands.insert(collider);
+        }
+        None => {
+            entity_commands.remove::<Collider>();
+        }
+    }
+}
+
+/// Greedy-meshes one chunk: sweeps each of the 6 face directions, masks
+/// visible faces (the neighbor across the face is empty), and merges
+/// adjacent equal mask cells into the largest possible quads. Returns `None`
+/// if the chunk has no visible faces at all.
+fn build_chunk_mesh(world: &VoxelWorld, chunk_coord: IVec3) -> Option<Mesh> {
+    let is_solid = |local: IVec3| world.is_solid(chunk_coord * CHUNK_SIZE + local);
+
+    let mut positions = Vec::new();
+    let mut normals = Vec::new();
+    let mut uvs = Vec::new();
+    let mut indices = Vec::new();
+
+    for axis in 0..3 {
+        greedy_mesh_axis(
+            axis,
+            &is_solid,
+            &mut positions,
+            &mut normals,
+            &mut uvs,
+            &mut indices,
+        );
+    }
+
+    if positions.is_empty() {
+        return None;
+    }
+
+    let mut mesh = Mesh::new(
+        PrimitiveTopology::TriangleList,
+        RenderAssetUsages::default(),
+    );
+    mesh.insert_attribute(Mesh::ATTRIBUTE_POSITION, positions);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_NORMAL, normals);
+    mesh.insert_attribute(Mesh::ATTRIBUTE_UV_0, uvs);
+    mesh.insert_indices(Indices::U32(indices));
+    Some(mesh)
+}
+
+fn greedy_mesh_axis(
+    axis: usize,
+    is_solid: &impl Fn(IVec3) -> bool,
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    uvs: &mut Vec<[f32; 2]>,
+    indices: &mut Vec<u32>,
+) {
+    let u = (axis + 1) % 3;
+    let v = (axis + 2) % 3;
+    let size = CHUNK_SIZE;
+
+    let mut step = IVec3::ZERO;
+    step[axis] = 1;
+
+    // `backface` picks which side of the boundary plane at `layer` we're
+    // exposing: the -axis face of a solid block at `layer` (backface), or the
+    // +axis face of a solid block at `layer - 1`.
+    for backface in [true, false] {
+        for layer in 0..=size {
+            let mut mask = vec![false; (size * size) as usize];
+            for a in 0..size {
+                for b in 0..size {
+                    let mut current = IVec3::ZERO;
+                    current[axis] = layer;
+                    current[v] = a;
+                    current[u] = b;
+                    let other = current - step;
+
+                    mask[(a * size + b) as usize] = if backface {
+                        is_solid(current) && !is_solid(other)
+                    } else {
+                        !is_solid(current) && is_solid(other)
+                    };
+                }
+            }
+
+            let mut visited = vec![false; (size * size) as usize];
+            for a in 0..size {
+                for b in 0..size {
+                    let idx = (a * size + b) as usize;
+                    if visited[idx] || !mask[idx] {
+                        continue;
+                    }
+
+                    let mut width = 1;
+                    while b + width < size
+                        && !visited[(a * size + b + width) as usize]
+                        && mask[(a * size + b + width) as usize]
+                    {
+                        width += 1;
+                    }
+
+                    let mut height = 1;
+                    'grow: while a + height < size {
+                        for k in 0..width {
+                            let idx2 = ((a + height) * size + b + k) as usize;
+                            if visited[idx2] || !mask[idx2] {
+                                break 'grow;
+                            }
+                        }
+                        height += 1;
+                    }
+
+                    for hh in 0..height {
+                        for ww in 0..width {
+                            visited[((a + hh) * size + b + ww) as usize] = true;
+                        }
+                    }
+
+                    emit_quad(
+                        axis, u, v, layer, a, b, height, width, backface, positions, normals,
+                        uvs, indices,
+                    );
+                }
+            }
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+fn emit_quad(
+    axis: usize,
+    u: usize,
+    v: usize,
+    layer: i32,
+    a: i32,
+    b: i32,
+    height: i32,
+    width: i32,
+    backface: bool,
+    positions: &mut Vec<[f32; 3]>,
+    normals: &mut Vec<[f32; 3]>,
+    uvs: &mut Vec<[f32; 2]>,
+    indices: &mut Vec<u32>,
+) {
+    let mut origin = [0.0f32; 3];
+    origin[axis] = layer as f32;
+    origin[v] = a as f32;
+    origin[u] = b as f32;
+
+    let mut du = [0.0f32; 3];
+    du[u] = width as f32;
+    let mut dv = [0.0f32; 3];
+    dv[v] = height as f32;
+
+    let p0 = origin;
+    let p1 = add(origin, du);
+    let p2 = add(add(origin, du), dv);
+    let p3 = add(origin, dv);
+
+    let mut normal = [0.0f32; 3];
+    normal[axis] = if backface { -1.0 } else { 1.0 };
+
+    let start = positions.len() as u32;
+    positions.extend([p0, p1, p2, p3]);
+    normals.extend([normal; 4]);
+    uvs.extend([
+        [0.0, 0.0],
+        [width as f32, 0.0],
+        [width as f32, height as f32],
+        [0.0, height as f32],
+    ]);
+
+    if backface {
+        indices.extend([start, start + 2, start + 1, start, start + 3, start + 2]);
+    } else {
+        indices.extend([start, start + 1, start + 2, start, start + 2, start + 3]);
+    }
+}
+
+fn add(a: [f32; 3], b: [f32; 3]) -> [f32; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}